@@ -1,6 +1,10 @@
 extern crate web_sys;
 
 use rxrust::prelude::*;
+use std::cell::{Cell, RefCell};
+use std::collections::HashSet;
+use std::rc::Rc;
+use std::time::Duration;
 use wasm_bindgen::prelude::*;
 
 // A macro to provide 'println!(..)'-style syntax for 'console.log' logging.
@@ -16,6 +20,13 @@ extern "C" {
     fn alert(s: &str);
 }
 
+// A helper to return the current emission index and advance the counter.
+fn next_index(index: &Cell<usize>) -> usize {
+    let i = index.get();
+    index.set(i + 1);
+    i
+}
+
 #[wasm_bindgen]
 pub fn example_basic() {
     log!("example_basic() - start");
@@ -88,3 +99,423 @@ pub fn example_skip_last() {
 
     log!("example_skip_last() - end");
 }
+
+#[wasm_bindgen]
+pub fn example_with_latest_from() {
+    log!("example_with_latest_from() - start");
+
+    // create a clicks stream and two state streams
+    let mut clicks: LocalSubject<i32, ()> = LocalSubject::new();
+    let mut width: LocalSubject<i32, ()> = LocalSubject::new();
+    let mut height: LocalSubject<i32, ()> = LocalSubject::new();
+    // read the latest value of every state stream on each click
+    let with_latest_from = clicks
+        .clone()
+        .with_latest_from(width.clone())
+        .with_latest_from(height.clone())
+        .map(|((click, width), height)| (click, width, height));
+
+    // "(2, 640, 480), (3, 800, 480), (4, 800, 480)" will be printed
+    with_latest_from.subscribe(|v| log!("{:?} ", v, ));
+    width.next(640);
+    // dropped, 'height' has no value yet
+    clicks.next(1);
+    height.next(480);
+    clicks.next(2);
+    width.next(800);
+    clicks.next(3);
+    // a completed state stream keeps its latest value
+    width.complete();
+    clicks.next(4);
+
+    log!("example_with_latest_from() - end");
+}
+
+#[wasm_bindgen]
+pub fn example_scan() {
+    log!("example_scan() - start");
+
+    // create an numbers stream
+    let numbers = observable::from_iter(1..=4);
+    // accumulate a running sum without a seed,
+    // the first value passes through unchanged
+    let running_sum = numbers
+        .clone()
+        .scan_initial(None, |acc: Option<i32>, v| Some(acc.map_or(v, |a| a + v)))
+        .map(Option::unwrap);
+
+    // "1, 3, 6, 10" will be printed
+    running_sum.subscribe(|v| log!("{} ", v, ));
+
+    log!("example_scan() - end");
+}
+
+#[wasm_bindgen]
+pub fn example_throttle_last() {
+    log!("example_throttle_last() - start");
+
+    // create an numbers stream
+    let numbers = observable::from_iter(0..10);
+    // keep only the last value of every three values, no scheduler needed
+    let throttle_last = numbers
+        .clone()
+        .buffer_with_count(3)
+        .map(|v| *v.last().unwrap());
+
+    // "2, 5, 8, 9" will be printed
+    throttle_last.subscribe(|v| log!("{} ", v, ));
+
+    log!("example_throttle_last() - end");
+}
+
+#[wasm_bindgen]
+pub fn example_from_fn() {
+    log!("example_from_fn() - start");
+
+    // create a countdown stream from a generator function,
+    // it completes when the generator returns 'None'
+    let mut count = 5;
+    let countdown = observable::from_iter(std::iter::from_fn(move || {
+        if count > 0 {
+            count -= 1;
+            Some(count + 1)
+        } else {
+            None
+        }
+    }));
+
+    // "5, 4, 3, 2, 1" will be printed
+    countdown.subscribe(|v| log!("{} ", v, ));
+
+    log!("example_from_fn() - end");
+}
+
+#[wasm_bindgen]
+pub fn example_max_skip_nan() {
+    log!("example_max_skip_nan() - start");
+
+    // create an numbers stream containing 'NaN'
+    let numbers = observable::from_iter(vec![1.5, f64::NAN, 4.5, 3.0]);
+    // 'PartialOrd' can't order 'NaN', so drop it before comparing
+    let max = numbers.clone().filter(|v| !v.is_nan()).max();
+
+    // "4.5" will be printed
+    max.subscribe(|v| log!("{} ", v, ));
+
+    log!("example_max_skip_nan() - end");
+}
+
+#[wasm_bindgen]
+pub fn example_scan_while() {
+    log!("example_scan_while() - start");
+
+    // create an numbers stream
+    let numbers = observable::from_iter(1..=10);
+    // accumulate a running sum and stop once the total exceeds 10
+    let scan_while = numbers
+        .clone()
+        .scan_initial(0, |acc, v| acc + v)
+        .take_while(|total| *total <= 10);
+
+    // "1, 3, 6, 10" will be printed
+    scan_while.subscribe(|v| log!("{} ", v, ));
+
+    log!("example_scan_while() - end");
+}
+
+#[wasm_bindgen]
+pub fn example_of_result() {
+    log!("example_of_result() - start");
+
+    // create a stream emitting one value, then completing
+    let ok = observable::of_result(Ok::<i32, &str>(1));
+    // create a stream erroring immediately, without any value
+    let err = observable::of_result(Err::<i32, &str>("failed"));
+    let throw = observable::throw("failed");
+
+    // "1" will be printed
+    ok.subscribe_err(|v| log!("{} ", v, ), |e| log!("error: {} ", e, ));
+    // "error: failed" will be printed twice
+    err.subscribe_err(|v| log!("{} ", v, ), |e| log!("error: {} ", e, ));
+    throw.subscribe_err(|_: ()| {}, |e| log!("error: {} ", e, ));
+
+    log!("example_of_result() - end");
+}
+
+#[wasm_bindgen]
+pub fn example_of_option() {
+    log!("example_of_option() - start");
+
+    // create a stream emitting one value for 'Some', nothing for 'None'
+    let some = observable::of_option(Some(1));
+    let none = observable::of_option(None::<i32>);
+    // create a stream producing its value lazily at subscription time
+    let lazy = observable::of_fn(|| {
+        log!("of_fn() - evaluated");
+        2
+    });
+
+    // "1" will be printed
+    some.subscribe(|v| log!("{} ", v, ));
+    // nothing will be printed
+    none.subscribe(|v| log!("{} ", v, ));
+    // "of_fn() - evaluated", then "2" will be printed
+    lazy.subscribe(|v| log!("{} ", v, ));
+
+    log!("example_of_option() - end");
+}
+
+#[wasm_bindgen]
+pub fn example_diff() {
+    log!("example_diff() - start");
+
+    // create an numbers stream
+    let numbers = observable::from_iter(vec![1, 4, 9, 16]);
+    // pair each value with the next one and emit the difference
+    let diff = numbers.clone().pairwise().map(|(prev, cur)| cur - prev);
+
+    // "3, 5, 7" will be printed
+    diff.subscribe(|v| log!("{} ", v, ));
+
+    log!("example_diff() - end");
+}
+
+#[wasm_bindgen]
+pub fn example_take_with_overflow() {
+    log!("example_take_with_overflow() - start");
+
+    // create an numbers stream
+    let numbers = observable::from_iter(1..=20);
+    // forward the first 'count' values but keep counting the rest,
+    // so the summary on completion can tell whether values were cut off
+    let count = 10;
+    let seen = Rc::new(Cell::new(0));
+    let take_with_overflow = numbers.clone().filter({
+        let seen = seen.clone();
+        move |_| {
+            seen.set(seen.get() + 1);
+            seen.get() <= count
+        }
+    });
+
+    // "1, 2, 3, 4, 5, 6, 7, 8, 9, 10", then "showing 10 of 20 values" will be printed
+    take_with_overflow.subscribe_all(
+        |v| log!("{} ", v, ),
+        |_| {},
+        move || {
+            if seen.get() > count {
+                log!("showing {} of {} values", count, seen.get());
+            } else {
+                log!("showing all {} values", seen.get());
+            }
+        },
+    );
+
+    log!("example_take_with_overflow() - end");
+}
+
+#[wasm_bindgen]
+pub fn example_distinct_tag() {
+    log!("example_distinct_tag() - start");
+
+    // create an numbers stream
+    let numbers = observable::from_iter(vec![1, 2, 1, 3, 2]);
+    // tag each value with whether it is seen for the first time,
+    // instead of dropping duplicates like 'distinct' does
+    let seen = RefCell::new(HashSet::new());
+    let distinct_tag = numbers
+        .clone()
+        .map(move |v| (v, seen.borrow_mut().insert(v)));
+
+    // "(1, true), (2, true), (1, false), (3, true), (2, false)" will be printed
+    distinct_tag.subscribe(|v| log!("{:?} ", v, ));
+
+    log!("example_distinct_tag() - end");
+}
+
+#[wasm_bindgen]
+pub fn example_map_with_index() {
+    log!("example_map_with_index() - start");
+
+    // create a words stream
+    let words = observable::from_iter(vec!["a", "b", "c", "d"]);
+    // pass a zero-based emission index along with each value,
+    // the 'Cell' counter is not 'Sync', so this only works for
+    // local observables and can't go through 'into_shared'
+    let index = Cell::new(0);
+    let map_with_index = words
+        .clone()
+        .map(move |v| format!("{}:{}", next_index(&index), v));
+    let index = Cell::new(0);
+    let filter_with_index = words.clone().filter(move |_| next_index(&index) % 2 == 0);
+
+    // "0:a, 1:b, 2:c, 3:d" will be printed
+    map_with_index.subscribe(|v| log!("{} ", v, ));
+    // "a, c" will be printed
+    filter_with_index.subscribe(|v| log!("{} ", v, ));
+
+    log!("example_map_with_index() - end");
+}
+
+#[wasm_bindgen]
+pub fn example_throttle_first() {
+    log!("example_throttle_first() - start");
+
+    // create an numbers stream
+    let numbers = observable::from_iter(0..10);
+    // forward the first value of every three values as soon as it arrives,
+    // no scheduler needed, see 'example_map_with_index' for the index
+    let index = Cell::new(0);
+    let throttle_first = numbers.clone().filter(move |_| next_index(&index) % 3 == 0);
+
+    // "0, 3, 6, 9" will be printed
+    throttle_first.subscribe(|v| log!("{} ", v, ));
+
+    log!("example_throttle_first() - end");
+}
+
+#[wasm_bindgen]
+pub fn example_enumerate() {
+    log!("example_enumerate() - start");
+
+    // create a words stream
+    let words = observable::from_iter(vec!["a", "b", "c"]);
+    // pair each value with a zero-based index like 'Iterator::enumerate',
+    // the index is kept by 'scan_initial' instead of a captured counter
+    let enumerate = words
+        .clone()
+        .scan_initial(None, |acc: Option<(usize, &str)>, v| {
+            Some((acc.map_or(0, |(i, _)| i + 1), v))
+        })
+        .map(Option::unwrap);
+
+    // every subscription starts from the initial value, so
+    // "(0, "a"), (1, "b"), (2, "c")" will be printed twice
+    enumerate.clone().subscribe(|v| log!("{:?} ", v, ));
+    enumerate.subscribe(|v| log!("{:?} ", v, ));
+
+    log!("example_enumerate() - end");
+}
+
+#[wasm_bindgen]
+pub fn example_buffer_with_count_and_time() {
+    log!("example_buffer_with_count_and_time() - start");
+
+    // create an numbers stream
+    let numbers = observable::from_iter(0..10);
+    // emit a buffer when it holds three values or when a second has passed,
+    // whichever comes first
+    let timespan = Duration::from_secs(1);
+    let buffer = numbers
+        .clone()
+        .buffer_with_count_and_time(3, timespan, LocalSpawner {});
+
+    // "[0, 1, 2], [3, 4, 5], [6, 7, 8], [9]" will be printed
+    buffer.subscribe(|v| log!("{:?} ", v, ));
+
+    log!("example_buffer_with_count_and_time() - end");
+}
+
+#[wasm_bindgen]
+pub fn example_throttle_time() {
+    log!("example_throttle_time() - start");
+
+    // create an numbers stream emitting every 100ms
+    let numbers = observable::interval(Duration::from_millis(100), LocalSpawner {}).take(10);
+    // emit the first value of every 250ms window
+    let leading = numbers.clone().throttle_time(
+        Duration::from_millis(250),
+        ThrottleEdge::Leading,
+        LocalSpawner {},
+    );
+    // emit the last value of every 250ms window
+    let tailing = numbers.clone().throttle_time(
+        Duration::from_millis(250),
+        ThrottleEdge::Tailing,
+        LocalSpawner {},
+    );
+
+    // "0, 3, 6, 9" will be printed
+    leading.subscribe(|v| log!("leading: {} ", v, ));
+    // "2, 5, 8, 9" will be printed
+    tailing.subscribe(|v| log!("tailing: {} ", v, ));
+
+    log!("example_throttle_time() - end");
+}
+
+#[wasm_bindgen]
+pub fn example_distinct_until_changed() {
+    log!("example_distinct_until_changed() - start");
+
+    // create an numbers stream
+    let numbers = observable::from_iter(vec![1, 1, 2, 2, 1, 3, 3]);
+    // drop a value only if it equals the previous one,
+    // unlike 'distinct' which drops every value seen before
+    let distinct_until_changed = numbers.clone().distinct_until_changed();
+
+    // "1, 2, 1, 3" will be printed
+    distinct_until_changed.subscribe(|v| log!("{} ", v, ));
+
+    log!("example_distinct_until_changed() - end");
+}
+
+#[wasm_bindgen]
+pub fn example_distinct_key() {
+    log!("example_distinct_key() - start");
+
+    // create a words stream
+    let words = observable::from_iter(vec!["apple", "avocado", "banana", "cherry", "blueberry"]);
+    // deduplicate by the first letter instead of the whole value
+    let seen = RefCell::new(HashSet::new());
+    let distinct_key = words
+        .clone()
+        .filter(move |v| seen.borrow_mut().insert(v.chars().next()));
+
+    // "apple, banana, cherry" will be printed
+    distinct_key.subscribe(|v| log!("{} ", v, ));
+
+    log!("example_distinct_key() - end");
+}
+
+#[wasm_bindgen]
+pub fn example_element_at() {
+    log!("example_element_at() - start");
+
+    // create an numbers stream
+    let numbers = observable::from_iter(1..=5);
+    let element_at = numbers.clone().skip(2).first();
+    // fall back to a default value when the stream is too short
+    let element_at_or = numbers.clone().skip(10).first_or(0);
+
+    // "3" will be printed
+    element_at.subscribe(|v| log!("{} ", v, ));
+    // "0" will be printed
+    element_at_or.subscribe(|v| log!("{} ", v, ));
+
+    log!("example_element_at() - end");
+}
+
+#[wasm_bindgen]
+pub fn example_combine_latest() {
+    log!("example_combine_latest() - start");
+
+    // create three numbers streams
+    let mut a: LocalSubject<i32, ()> = LocalSubject::new();
+    let mut b: LocalSubject<i32, ()> = LocalSubject::new();
+    let mut c: LocalSubject<i32, ()> = LocalSubject::new();
+    // combine the latest values of all streams whenever any of them emits
+    let combine_latest = a
+        .clone()
+        .combine_latest(b.clone(), |a, b| (a, b))
+        .combine_latest(c.clone(), |(a, b), c| (a, b, c));
+
+    // "(1, 2, 3), (4, 2, 3), (4, 2, 5)" will be printed
+    combine_latest.subscribe(|v| log!("{:?} ", v, ));
+    a.next(1);
+    b.next(2);
+    c.next(3);
+    a.next(4);
+    c.next(5);
+
+    log!("example_combine_latest() - end");
+}
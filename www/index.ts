@@ -5,3 +5,23 @@ wasm.example_first();
 wasm.example_last();
 wasm.example_skip();
 wasm.example_skip_last();
+wasm.example_with_latest_from();
+wasm.example_scan();
+wasm.example_throttle_last();
+wasm.example_from_fn();
+wasm.example_max_skip_nan();
+wasm.example_scan_while();
+wasm.example_of_result();
+wasm.example_of_option();
+wasm.example_diff();
+wasm.example_take_with_overflow();
+wasm.example_distinct_tag();
+wasm.example_map_with_index();
+wasm.example_throttle_first();
+wasm.example_enumerate();
+wasm.example_buffer_with_count_and_time();
+wasm.example_throttle_time();
+wasm.example_distinct_until_changed();
+wasm.example_distinct_key();
+wasm.example_element_at();
+wasm.example_combine_latest();